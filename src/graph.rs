@@ -1,8 +1,10 @@
+pub mod exact_solver;
 pub mod penalty_digraph;
 
-use std::{collections::{BTreeSet, HashMap, HashSet}, iter};
+use std::{cmp::Ordering, collections::{BTreeSet, HashMap, HashSet}, iter};
 
 use crate::error::Error;
+use crate::graph::penalty_digraph::PenaltyDigraph;
 
 
 
@@ -186,4 +188,330 @@ impl Graph {
 
         Ok(number_of_crossings)
     }
+
+    /// Computes the number of crossings for a specific ordering of the free nodes in O(E log V)
+    ///
+    /// This is an inversion-counting reformulation of [`Graph::compute_number_of_crossings_for_ordering`]:
+    /// for each fixed node in turn, the positions of its free-node neighbors are queried against a
+    /// Fenwick tree indexed by free-node position (counting already-inserted neighbors that lie further
+    /// right), then inserted. The naive method is kept around as a reference/test oracle for this one.
+    ///
+    /// The input ordering **must** contain all free nodes (and each exactly once), otherwise the function returns an error
+    pub fn compute_number_of_crossings_for_ordering_fast(
+        &self,
+        ordering: &Vec<usize>,
+    ) -> Result<usize, Error> {
+        if ordering.len() != self.number_of_free_nodes {
+            return Err(Error::ValueError(
+                "The ordering does not contain all free nodes".to_string(),
+            ));
+        }
+        let included_indices: HashSet<usize> = ordering.iter().cloned().collect();
+        if included_indices != (self.number_of_fixed_nodes..self.number_of_nodes).collect() {
+            return Err(Error::ValueError(
+                "The ordering does not contain all free nodes".to_string(),
+            ));
+        }
+
+        let mut positions = HashMap::new();
+        for (position, free_node_index) in ordering.iter().enumerate() {
+            positions.insert(*free_node_index, position);
+        }
+
+        let mut fenwick_tree = FenwickTree::new(self.number_of_free_nodes);
+        let mut number_of_crossings = 0;
+
+        for fixed_node_index in 0..self.number_of_fixed_nodes {
+            let neighbor_positions: Vec<usize> = self
+                .adjacency_list
+                .get(fixed_node_index)
+                .expect("Index must exist")
+                .iter()
+                .map(|neighbor_index| {
+                    *positions
+                        .get(neighbor_index)
+                        .expect("A position must have been found")
+                })
+                .collect();
+
+            // Query before inserting, so that neighbors of the same fixed node never cross each other.
+            for &position in &neighbor_positions {
+                number_of_crossings += fenwick_tree.count_inserted_strictly_greater_than(position);
+            }
+            for &position in &neighbor_positions {
+                fenwick_tree.insert(position);
+            }
+        }
+
+        Ok(number_of_crossings)
+    }
+
+    /// Computes the pairwise crossing matrix `c[u][v]` over free nodes, where `u` and `v` are given as
+    /// positions within the free-node set (`0` is the first free node, i.e. `number_of_fixed_nodes`)
+    ///
+    /// `c[u][v]` is the number of crossings incurred between the edges of `u` and `v` when `u` is placed
+    /// left of `v`: the number of neighbor pairs `(a, b)` with `a` a neighbor of `u`, `b` a neighbor of
+    /// `v` and `b < a` (over the fixed-node indices). This is the fundamental quantity every downstream
+    /// OCM heuristic and exact solver reduces to: the crossing number of any ordering is the sum of
+    /// `c[u][v]` over all pairs placed with `u` before `v`.
+    pub fn crossing_matrix(&self) -> Vec<Vec<usize>> {
+        let mut matrix = vec![vec![0; self.number_of_free_nodes]; self.number_of_free_nodes];
+
+        for free_node1 in 0..self.number_of_free_nodes {
+            let (rows_up_to_and_including_1, rows_after_1) = matrix.split_at_mut(free_node1 + 1);
+            let row1 = rows_up_to_and_including_1.last_mut().expect("the first half holds rows 0..=free_node1");
+
+            for (offset, row2) in rows_after_1.iter_mut().enumerate() {
+                let free_node2 = free_node1 + 1 + offset;
+                let (crossings_1_before_2, crossings_2_before_1) =
+                    self.crossing_pair(free_node1, free_node2).expect("Both indices are in bounds by construction");
+
+                row1[free_node2] = crossings_1_before_2;
+                row2[free_node1] = crossings_2_before_1;
+            }
+        }
+
+        matrix
+    }
+
+    /// Computes `(c[u][v], c[v][u])` for a single pair of free nodes (see [`Graph::crossing_matrix`]),
+    /// so that cyclic-penalty logic can reuse the pairwise computation without building the full matrix
+    pub fn crossing_pair(&self, free_node1: usize, free_node2: usize) -> Result<(usize, usize), Error> {
+        if free_node1 >= self.number_of_free_nodes || free_node2 >= self.number_of_free_nodes {
+            return Err(Error::IndexError("Free node index is out of bounds".to_string()));
+        }
+
+        let neighbors1 = self
+            .adjacency_list
+            .get(self.number_of_fixed_nodes + free_node1)
+            .expect("Index must exist");
+        let neighbors2 = self
+            .adjacency_list
+            .get(self.number_of_fixed_nodes + free_node2)
+            .expect("Index must exist");
+
+        let mut crossings_1_before_2 = 0;
+        let mut crossings_2_before_1 = 0;
+        for &neighbor1 in neighbors1 {
+            for &neighbor2 in neighbors2 {
+                if neighbor2 < neighbor1 {
+                    crossings_1_before_2 += 1;
+                } else if neighbor1 < neighbor2 {
+                    crossings_2_before_1 += 1;
+                }
+            }
+        }
+
+        Ok((crossings_1_before_2, crossings_2_before_1))
+    }
+
+    /// Tries to find a provably optimal ordering of the free nodes by orienting the pairwise penalty
+    /// digraph and topologically sorting it with Kahn's algorithm (see [`penalty_digraph`])
+    ///
+    /// Returns `Ok((ordering, crossing_number))` if the penalty digraph happens to be acyclic, in which
+    /// case the ordering is optimal. Returns `Err(Error::ValueError(_))` if a cycle is detected,
+    /// signalling that a heuristic or exact fallback (e.g. [`Graph::order_by_median`]) should be used instead.
+    pub fn solve_topological(&self) -> Result<(Vec<usize>, usize), Error> {
+        let digraph = PenaltyDigraph::from_crossing_matrix(&self.crossing_matrix());
+        let (order, crossing_number) = digraph.topological_order()?;
+
+        let ordering = order
+            .into_iter()
+            .map(|free_node_position| self.number_of_fixed_nodes + free_node_position)
+            .collect();
+
+        Ok((ordering, crossing_number))
+    }
+
+    /// Orders the free nodes by the average (barycenter) index of their fixed neighbors
+    ///
+    /// Free nodes with no neighbors keep their original relative order (stably sorted to the end);
+    /// ties among the remaining free nodes are broken deterministically by free node index.
+    pub fn order_by_barycenter(&self) -> Vec<usize> {
+        self.order_free_nodes_by(|neighbors| {
+            neighbors.iter().sum::<usize>() as f64 / neighbors.len() as f64
+        })
+    }
+
+    /// Orders the free nodes by the median index of their fixed neighbors (Eades-Wormald), which gives
+    /// a 3-approximation of the minimum crossing number
+    ///
+    /// Free nodes with no neighbors keep their original relative order (stably sorted to the end);
+    /// ties among the remaining free nodes are broken deterministically by free node index.
+    pub fn order_by_median(&self) -> Vec<usize> {
+        self.order_free_nodes_by(|neighbors| {
+            let sorted_neighbors: Vec<usize> = neighbors.iter().cloned().collect();
+            let middle = sorted_neighbors.len() / 2;
+
+            if sorted_neighbors.len().is_multiple_of(2) {
+                (sorted_neighbors[middle - 1] + sorted_neighbors[middle]) as f64 / 2.0
+            } else {
+                sorted_neighbors[middle] as f64
+            }
+        })
+    }
+
+    /// Finds an exact minimum-crossing ordering of the free nodes via branch-and-bound over the
+    /// pairwise crossing matrix (see [`exact_solver`]), seeded with the median heuristic
+    pub fn solve_exact(&self) -> Result<(Vec<usize>, usize), Error> {
+        if self.number_of_free_nodes == 0 {
+            return Ok((Vec::new(), 0));
+        }
+
+        let crossing_matrix = self.crossing_matrix();
+        let initial_ordering: Vec<usize> = self
+            .order_by_median()
+            .into_iter()
+            .map(|free_node_index| free_node_index - self.number_of_fixed_nodes)
+            .collect();
+
+        let (order, crossing_number) = exact_solver::branch_and_bound(&crossing_matrix, initial_ordering);
+
+        let ordering = order
+            .into_iter()
+            .map(|free_node_position| self.number_of_fixed_nodes + free_node_position)
+            .collect();
+
+        Ok((ordering, crossing_number))
+    }
+}
+
+// PRIVATE METHODS ---------------------------------------------------------------------------------
+impl Graph {
+    /// Orders the free nodes by an ascending numeric key derived from their fixed neighbors
+    ///
+    /// Free nodes with no neighbors are given no key and are stably sorted to the end, so their
+    /// original relative order among themselves is preserved.
+    fn order_free_nodes_by(&self, key_fn: impl Fn(&BTreeSet<usize>) -> f64) -> Vec<usize> {
+        let mut ordering: Vec<usize> = (self.number_of_fixed_nodes..self.number_of_nodes).collect();
+
+        ordering.sort_by(|&free_node1, &free_node2| {
+            let neighbors1 = self.adjacency_list.get(free_node1).expect("Index must exist");
+            let neighbors2 = self.adjacency_list.get(free_node2).expect("Index must exist");
+
+            let key1 = (!neighbors1.is_empty()).then(|| key_fn(neighbors1));
+            let key2 = (!neighbors2.is_empty()).then(|| key_fn(neighbors2));
+
+            match (key1, key2) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(key1), Some(key2)) => key1.partial_cmp(&key2).expect("Keys are always finite"),
+            }
+        });
+
+        ordering
+    }
+}
+
+// PRIVATE STRUCTS ---------------------------------------------------------------------------------
+
+/// A Fenwick tree (binary indexed tree) over free-node positions, used to count inversions while
+/// sweeping fixed nodes in ascending order
+struct FenwickTree {
+    counts: Vec<usize>,
+    number_of_inserted: usize,
+}
+
+impl FenwickTree {
+    fn new(size: usize) -> FenwickTree {
+        FenwickTree {
+            counts: vec![0; size + 1],
+            number_of_inserted: 0,
+        }
+    }
+
+    fn insert(&mut self, position: usize) {
+        self.number_of_inserted += 1;
+        let mut index = position + 1;
+        while index < self.counts.len() {
+            self.counts[index] += 1;
+            index += index & index.wrapping_neg();
+        }
+    }
+
+    /// Returns the number of already-inserted positions that are less than or equal to `position`
+    fn prefix_count(&self, position: usize) -> usize {
+        let mut index = position + 1;
+        let mut sum = 0;
+        while index > 0 {
+            sum += self.counts[index];
+            index -= index & index.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Returns the number of already-inserted positions that are strictly greater than `position`
+    fn count_inserted_strictly_greater_than(&self, position: usize) -> usize {
+        self.number_of_inserted - self.prefix_count(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::seq::SliceRandom;
+
+    use super::Graph;
+    use crate::graph_builder::GraphBuilder;
+
+    #[test]
+    fn fast_crossing_count_matches_naive_oracle_on_random_orderings() {
+        for _ in 0..500 {
+            let graph = GraphBuilder::build_random_graph(5, 6, 15).unwrap();
+
+            let mut ordering: Vec<usize> =
+                (graph.number_of_fixed_nodes()..graph.number_of_nodes()).collect();
+            ordering.shuffle(&mut rand::thread_rng());
+
+            let naive = graph.compute_number_of_crossings_for_ordering(&ordering).unwrap();
+            let fast = graph.compute_number_of_crossings_for_ordering_fast(&ordering).unwrap();
+
+            assert_eq!(fast, naive);
+        }
+    }
+
+    #[test]
+    fn fast_crossing_count_matches_default_ordering_oracle() {
+        for _ in 0..20 {
+            let graph = GraphBuilder::build_random_graph(5, 6, 15).unwrap();
+            let default_ordering: Vec<usize> =
+                (graph.number_of_fixed_nodes()..graph.number_of_nodes()).collect();
+
+            let naive = graph.compute_number_of_crossings_with_default_ordering().unwrap();
+            let fast = graph
+                .compute_number_of_crossings_for_ordering_fast(&default_ordering)
+                .unwrap();
+
+            assert_eq!(fast, naive);
+        }
+    }
+
+    #[test]
+    fn ordering_heuristics_produce_valid_permutations_of_free_nodes() {
+        for _ in 0..20 {
+            let graph = GraphBuilder::build_random_graph(5, 6, 15).unwrap();
+            let expected: HashSet<usize> =
+                (graph.number_of_fixed_nodes()..graph.number_of_nodes()).collect();
+
+            let median_ordering: HashSet<usize> = graph.order_by_median().into_iter().collect();
+            let barycenter_ordering: HashSet<usize> = graph.order_by_barycenter().into_iter().collect();
+
+            assert_eq!(median_ordering, expected);
+            assert_eq!(barycenter_ordering, expected);
+        }
+    }
+
+    #[test]
+    fn ordering_heuristics_keep_zero_degree_free_nodes_in_their_original_relative_order() {
+        let mut graph = Graph::new(3, 5);
+        graph.add_edge(0, 4).unwrap();
+        graph.add_edge(2, 6).unwrap();
+
+        // Free nodes 3, 5 and 7 have no neighbors and must stay in ascending order at the tail,
+        // behind the two free nodes that do have a key to sort by.
+        assert_eq!(graph.order_by_barycenter(), vec![4, 6, 3, 5, 7]);
+        assert_eq!(graph.order_by_median(), vec![4, 6, 3, 5, 7]);
+    }
 }
@@ -0,0 +1,170 @@
+/// Finds a minimum-crossing ordering of the free nodes via branch-and-bound over the pairwise crossing
+/// matrix (see [`crate::graph::Graph::crossing_matrix`])
+///
+/// The search fixes the ordering one free node at a time, maintaining the cost already incurred
+/// between the decided prefix and pruning whenever that cost plus the unavoidable lower bound on the
+/// still-undecided free nodes (`sum over remaining unordered pairs of min(c[u][v], c[v][u])`) is no
+/// better than the incumbent. `initial_ordering` seeds the incumbent (pass in a heuristic ordering such
+/// as [`crate::graph::Graph::order_by_median`] to prune aggressively from the start) and is given as
+/// free-node positions, i.e. indices into `crossing_matrix`.
+pub fn branch_and_bound(crossing_matrix: &Vec<Vec<usize>>, initial_ordering: Vec<usize>) -> (Vec<usize>, usize) {
+    let number_of_free_nodes = crossing_matrix.len();
+
+    let mut best_cost = ordering_cost(crossing_matrix, &initial_ordering);
+    let mut best_ordering = initial_ordering;
+
+    let mut prefix = Vec::with_capacity(number_of_free_nodes);
+    let mut used = vec![false; number_of_free_nodes];
+
+    branch(crossing_matrix, &mut prefix, &mut used, 0, &mut best_ordering, &mut best_cost);
+
+    (best_ordering, best_cost)
+}
+
+fn branch(
+    crossing_matrix: &Vec<Vec<usize>>,
+    prefix: &mut Vec<usize>,
+    used: &mut Vec<bool>,
+    cost_so_far: usize,
+    best_ordering: &mut Vec<usize>,
+    best_cost: &mut usize,
+) {
+    let number_of_free_nodes = crossing_matrix.len();
+
+    if prefix.len() == number_of_free_nodes {
+        if cost_so_far < *best_cost {
+            *best_cost = cost_so_far;
+            *best_ordering = prefix.clone();
+        }
+        return;
+    }
+
+    if cost_so_far + remaining_lower_bound(crossing_matrix, used) >= *best_cost {
+        return;
+    }
+
+    for candidate in 0..number_of_free_nodes {
+        if used[candidate] {
+            continue;
+        }
+
+        let added_cost: usize = prefix.iter().map(|&placed| crossing_matrix[placed][candidate]).sum();
+
+        prefix.push(candidate);
+        used[candidate] = true;
+
+        branch(crossing_matrix, prefix, used, cost_so_far + added_cost, best_ordering, best_cost);
+
+        prefix.pop();
+        used[candidate] = false;
+    }
+}
+
+/// The unavoidable lower bound contributed by pairs where neither node has been placed yet
+fn remaining_lower_bound(crossing_matrix: &Vec<Vec<usize>>, used: &Vec<bool>) -> usize {
+    let number_of_free_nodes = crossing_matrix.len();
+    let mut lower_bound = 0;
+
+    for node1 in 0..number_of_free_nodes {
+        if used[node1] {
+            continue;
+        }
+        for node2 in (node1 + 1)..number_of_free_nodes {
+            if used[node2] {
+                continue;
+            }
+            lower_bound += crossing_matrix[node1][node2].min(crossing_matrix[node2][node1]);
+        }
+    }
+
+    lower_bound
+}
+
+fn ordering_cost(crossing_matrix: &Vec<Vec<usize>>, ordering: &Vec<usize>) -> usize {
+    let mut cost = 0;
+    for (position1, &node1) in ordering.iter().enumerate() {
+        for &node2 in ordering.iter().skip(position1 + 1) {
+            cost += crossing_matrix[node1][node2];
+        }
+    }
+    cost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graph::Graph, graph_builder::GraphBuilder};
+
+    /// Exhaustively tries every permutation of the free nodes and returns the minimum crossing count
+    fn brute_force_minimum_crossings(graph: &Graph) -> usize {
+        let mut free_node_positions: Vec<usize> = (0..graph.number_of_free_nodes()).collect();
+        let mut best_crossings = usize::MAX;
+
+        permute(&mut free_node_positions, 0, &mut |permutation| {
+            let ordering: Vec<usize> = permutation
+                .iter()
+                .map(|&position| graph.number_of_fixed_nodes() + position)
+                .collect();
+            let crossings = graph
+                .compute_number_of_crossings_for_ordering(&ordering)
+                .expect("ordering contains every free node exactly once");
+            best_crossings = best_crossings.min(crossings);
+        });
+
+        best_crossings
+    }
+
+    fn permute(values: &mut Vec<usize>, start: usize, visit: &mut impl FnMut(&Vec<usize>)) {
+        if start == values.len() {
+            visit(values);
+            return;
+        }
+        for i in start..values.len() {
+            values.swap(start, i);
+            permute(values, start + 1, visit);
+            values.swap(start, i);
+        }
+    }
+
+    #[test]
+    fn solve_exact_matches_brute_force_on_random_graphs() {
+        for _ in 0..20 {
+            let graph = GraphBuilder::build_random_graph(4, 5, 10).expect("10 edges fit in a 4x5 graph");
+
+            let (_, exact_crossings) = graph.solve_exact().expect("number_of_free_nodes is not 0");
+            let expected_crossings = brute_force_minimum_crossings(&graph);
+
+            assert_eq!(exact_crossings, expected_crossings);
+        }
+    }
+
+    #[test]
+    fn solve_exact_matches_brute_force_on_cyclic_preference_triple() {
+        // Neighbor sets chosen so the pairwise preferred-orientation digraph (see `penalty_digraph`)
+        // has a cycle among the free nodes; regression test for the since-removed `is_dominated`
+        // pruning rule, which silently returned a non-optimal ordering on instances like this one.
+        let number_of_fixed_nodes = 8;
+        let neighbor_sets: Vec<Vec<usize>> = vec![
+            vec![3, 4, 7],
+            vec![0, 1, 2, 7],
+            vec![0, 6],
+            vec![0, 2, 3, 4, 5],
+            vec![1, 2, 3],
+            vec![1, 3, 5, 6, 7],
+        ];
+
+        let mut graph = Graph::new(number_of_fixed_nodes, neighbor_sets.len());
+        for (free_node_offset, neighbors) in neighbor_sets.iter().enumerate() {
+            for &fixed_node_index in neighbors {
+                graph
+                    .add_edge(fixed_node_index, number_of_fixed_nodes + free_node_offset)
+                    .unwrap();
+            }
+        }
+
+        let (_, exact_crossings) = graph.solve_exact().expect("number_of_free_nodes is not 0");
+        let expected_crossings = brute_force_minimum_crossings(&graph);
+
+        assert_eq!(exact_crossings, expected_crossings);
+        assert_eq!(exact_crossings, 55);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use crate::error::Error;
+
+/// A directed graph over free nodes, oriented from the pairwise crossing matrix
+///
+/// For every unordered pair `{u, v}` of free nodes, an arc `u -> v` is added whenever `c[u][v] <
+/// c[v][u]`, i.e. placing `u` before `v` is strictly cheaper; ties (`c[u][v] == c[v][u]`) are left
+/// unoriented since either order is equally good. Each arc carries the penalty `|c[u][v] - c[v][u]|`
+/// that would be incurred by violating it.
+pub struct PenaltyDigraph {
+    number_of_nodes: usize,
+    arcs: Vec<Vec<(usize, usize)>>,
+    lower_bound: usize,
+}
+
+impl PenaltyDigraph {
+    /// Builds the penalty digraph from a pairwise crossing matrix (see [`crate::graph::Graph::crossing_matrix`])
+    pub fn from_crossing_matrix(crossing_matrix: &Vec<Vec<usize>>) -> PenaltyDigraph {
+        let number_of_nodes = crossing_matrix.len();
+        let mut arcs = vec![Vec::new(); number_of_nodes];
+        let mut lower_bound = 0;
+
+        for node1 in 0..number_of_nodes {
+            for node2 in (node1 + 1)..number_of_nodes {
+                let crossings_1_before_2 = crossing_matrix[node1][node2];
+                let crossings_2_before_1 = crossing_matrix[node2][node1];
+
+                lower_bound += crossings_1_before_2.min(crossings_2_before_1);
+
+                match crossings_1_before_2.cmp(&crossings_2_before_1) {
+                    Ordering::Less => arcs[node1].push((node2, crossings_2_before_1 - crossings_1_before_2)),
+                    Ordering::Greater => arcs[node2].push((node1, crossings_1_before_2 - crossings_2_before_1)),
+                    Ordering::Equal => {}
+                }
+            }
+        }
+
+        PenaltyDigraph {
+            number_of_nodes,
+            arcs,
+            lower_bound,
+        }
+    }
+
+    /// Tries to find a provably optimal ordering of the free nodes via a topological sort (Kahn's algorithm)
+    ///
+    /// If the penalty digraph is acyclic, every arc can be respected simultaneously, so the resulting
+    /// order is optimal and its crossing number is exactly the unavoidable pairwise lower bound
+    /// accumulated while building the digraph. Returns `Err(Error::ValueError(_))` if a cycle is
+    /// detected, meaning no single order can respect every orientation and a heuristic or exact
+    /// fallback is required instead.
+    pub fn topological_order(&self) -> Result<(Vec<usize>, usize), Error> {
+        let mut in_degree = vec![0; self.number_of_nodes];
+        for neighbors in &self.arcs {
+            for &(successor, _) in neighbors {
+                in_degree[successor] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.number_of_nodes)
+            .filter(|&node| in_degree[node] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.number_of_nodes);
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &(successor, _) in &self.arcs[node] {
+                in_degree[successor] -= 1;
+                if in_degree[successor] == 0 {
+                    queue.push_back(successor);
+                }
+            }
+        }
+
+        if order.len() != self.number_of_nodes {
+            return Err(Error::ValueError(
+                "The penalty digraph contains a cycle, so no topological order exists".to_string(),
+            ));
+        }
+
+        Ok((order, self.lower_bound))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+
+    #[test]
+    fn solve_topological_matches_naive_crossing_count_on_an_acyclic_instance() {
+        // Each free node has a single, distinct fixed neighbor, so every pairwise comparison is
+        // decided and the preferred-orientation digraph is a strict total order (0 -> 1 -> 2).
+        let mut graph = Graph::new(4, 3);
+        graph.add_edge(0, 4).unwrap();
+        graph.add_edge(1, 5).unwrap();
+        graph.add_edge(2, 6).unwrap();
+
+        let (ordering, crossing_number) = graph.solve_topological().expect("digraph is acyclic");
+
+        assert_eq!(ordering, vec![4, 5, 6]);
+        assert_eq!(
+            crossing_number,
+            graph.compute_number_of_crossings_for_ordering(&ordering).unwrap()
+        );
+    }
+
+    #[test]
+    fn solve_topological_detects_a_cyclic_preference_triple() {
+        // Same cyclic instance as the exact_solver regression test: the preferred-orientation
+        // digraph among the free nodes contains a cycle, so no topological order exists.
+        let number_of_fixed_nodes = 8;
+        let neighbor_sets: Vec<Vec<usize>> = vec![
+            vec![3, 4, 7],
+            vec![0, 1, 2, 7],
+            vec![0, 6],
+            vec![0, 2, 3, 4, 5],
+            vec![1, 2, 3],
+            vec![1, 3, 5, 6, 7],
+        ];
+
+        let mut graph = Graph::new(number_of_fixed_nodes, neighbor_sets.len());
+        for (free_node_offset, neighbors) in neighbor_sets.iter().enumerate() {
+            for &fixed_node_index in neighbors {
+                graph
+                    .add_edge(fixed_node_index, number_of_fixed_nodes + free_node_offset)
+                    .unwrap();
+            }
+        }
+
+        assert!(graph.solve_topological().is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Write},
 };
 
 use rand::{seq::SliceRandom, thread_rng, Rng};
@@ -80,6 +80,72 @@ impl GraphBuilder {
         Ok(graph)
     }
 
+    /// Constructs a Graph from a whitespace-separated 0/1 adjacency matrix of size `fixed x free`
+    ///
+    /// Row `f`, column `u` of the matrix is expected to be `1` if fixed node `f` and free node `u` are
+    /// adjacent, and `0` otherwise. Every row must have the same number of columns.
+    pub fn build_graph_from_adjacency_matrix(filename: &str) -> Result<Graph, Error> {
+        let file = File::open(filename)?;
+        let rows: Vec<String> = BufReader::new(file)
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let number_of_fixed_nodes = rows.len();
+        let number_of_free_nodes = rows.first().map_or(0, |row| row.split_whitespace().count());
+
+        let mut graph = Graph::new(number_of_fixed_nodes, number_of_free_nodes);
+
+        for (fixed_node_index, row) in rows.iter().enumerate() {
+            let entries: Vec<&str> = row.split_whitespace().collect();
+            if entries.len() != number_of_free_nodes {
+                return Err(Error::ParseError(
+                    "Every row of the adjacency matrix must have the same number of columns".to_string(),
+                ));
+            }
+
+            for (free_node_offset, entry) in entries.iter().enumerate() {
+                match *entry {
+                    "1" => {
+                        graph.add_edge(fixed_node_index, number_of_fixed_nodes + free_node_offset)?;
+                    }
+                    "0" => {}
+                    _ => {
+                        return Err(Error::ParseError(format!(
+                            "Unexpected adjacency matrix entry '{}', expected 0 or 1",
+                            entry
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Writes a free-node ordering to the PACE `.sol`/`.ord` convention: one 1-based free-node index
+    /// per line, in order
+    ///
+    /// `ordering` is expected to contain the absolute node indices of a Graph with `number_of_fixed_nodes`
+    /// fixed nodes, as returned by e.g. [`Graph::order_by_median`](crate::graph::Graph::order_by_median).
+    pub fn write_ordering_to_file(
+        ordering: &Vec<usize>,
+        number_of_fixed_nodes: usize,
+        filename: &str,
+    ) -> Result<(), Error> {
+        let mut file = File::create(filename)?;
+
+        for &free_node_index in ordering {
+            let relative_index = free_node_index.checked_sub(number_of_fixed_nodes).ok_or(
+                Error::ValueError("The ordering contains an index that is not a free node".to_string()),
+            )?;
+            writeln!(file, "{}", relative_index + 1)?;
+        }
+
+        Ok(())
+    }
+
     /// Constructs a random graph
     pub fn build_random_graph(
         number_of_fixed_nodes: usize,
@@ -113,6 +179,66 @@ impl GraphBuilder {
 
         Ok(graph)
     }
+
+    /// Constructs a random bipartite graph using the Erdos-Renyi model
+    ///
+    /// Each of the `fixed * free` possible edges is included independently with probability `p`.
+    pub fn build_random_graph_erdos_renyi(
+        number_of_fixed_nodes: usize,
+        number_of_free_nodes: usize,
+        p: f64,
+    ) -> Result<Graph, Error> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(Error::ValueError(
+                "p must be a probability between 0 and 1".to_string(),
+            ));
+        }
+
+        let mut graph = Graph::new(number_of_fixed_nodes, number_of_free_nodes);
+        let mut rng = rand::thread_rng();
+
+        for fixed_node_index in 0..number_of_fixed_nodes {
+            for free_node_offset in 0..number_of_free_nodes {
+                if rng.gen_bool(p) {
+                    graph
+                        .add_edge(fixed_node_index, number_of_fixed_nodes + free_node_offset)
+                        .unwrap();
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Constructs a random bipartite graph where each free node's degree is drawn from `degree_distribution`
+    ///
+    /// Every free node draws a target degree (clamped to `[0, fixed]`) from the supplied distribution
+    /// and then connects to that many uniformly-chosen, distinct fixed nodes. The distribution can be
+    /// any closure, e.g. a power-law or fixed-mean sampler. The fixed nodes themselves are chosen with
+    /// `rand::thread_rng()`, so the resulting graph is not reproducible from a seed, even when
+    /// `degree_distribution` is seeded.
+    pub fn build_random_graph_with_degree_distribution(
+        number_of_fixed_nodes: usize,
+        number_of_free_nodes: usize,
+        mut degree_distribution: impl FnMut() -> usize,
+    ) -> Graph {
+        let mut graph = Graph::new(number_of_fixed_nodes, number_of_free_nodes);
+        let mut rng = rand::thread_rng();
+
+        for free_node_offset in 0..number_of_free_nodes {
+            let free_node_index = number_of_fixed_nodes + free_node_offset;
+            let degree = degree_distribution().min(number_of_fixed_nodes);
+
+            let mut fixed_node_indices: Vec<usize> = (0..number_of_fixed_nodes).collect();
+            fixed_node_indices.shuffle(&mut rng);
+
+            for &fixed_node_index in fixed_node_indices.iter().take(degree) {
+                graph.add_edge(fixed_node_index, free_node_index).unwrap();
+            }
+        }
+
+        graph
+    }
 }
 
 // PRIVATE METHODS ------------------------------------------------------------------
@@ -174,3 +300,115 @@ impl PLineInfo {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::GraphBuilder;
+
+    fn temp_file_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("pace_graph_builder_test_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn write_ordering_to_file_round_trips_through_the_pace_ord_convention() {
+        let path = temp_file_path("ordering.ord");
+        let number_of_fixed_nodes = 2;
+        let ordering = vec![3, 4, 2];
+
+        GraphBuilder::write_ordering_to_file(&ordering, number_of_fixed_nodes, path.to_str().unwrap()).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let parsed_relative_indices: Vec<usize> =
+            contents.lines().map(|line| line.parse().unwrap()).collect();
+
+        assert_eq!(parsed_relative_indices, vec![2, 3, 1]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn write_ordering_to_file_rejects_an_index_that_is_not_a_free_node() {
+        let path = temp_file_path("invalid_ordering.ord");
+
+        let result = GraphBuilder::write_ordering_to_file(&vec![0], 2, path.to_str().unwrap());
+
+        assert!(result.is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn build_graph_from_adjacency_matrix_places_edges_at_the_expected_absolute_indices() {
+        let path = temp_file_path("matrix.txt");
+        fs::write(&path, "1 0 1\n0 1 0\n").unwrap();
+
+        let graph = GraphBuilder::build_graph_from_adjacency_matrix(path.to_str().unwrap()).unwrap();
+
+        assert_eq!(graph.number_of_fixed_nodes(), 2);
+        assert_eq!(graph.number_of_free_nodes(), 3);
+        assert!(graph.does_edge_exist(0, 2).unwrap());
+        assert!(graph.does_edge_exist(0, 4).unwrap());
+        assert!(graph.does_edge_exist(1, 3).unwrap());
+        assert!(!graph.does_edge_exist(1, 2).unwrap());
+        assert!(!graph.does_edge_exist(1, 4).unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn build_graph_from_adjacency_matrix_rejects_ragged_rows() {
+        let path = temp_file_path("ragged_matrix.txt");
+        fs::write(&path, "1 0 1\n0 1\n").unwrap();
+
+        let result = GraphBuilder::build_graph_from_adjacency_matrix(path.to_str().unwrap());
+
+        assert!(result.is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn erdos_renyi_with_p_zero_has_no_edges() {
+        let graph = GraphBuilder::build_random_graph_erdos_renyi(4, 5, 0.0).unwrap();
+
+        assert_eq!(graph.number_of_edges(), 0);
+    }
+
+    #[test]
+    fn erdos_renyi_with_p_one_is_the_complete_bipartite_graph() {
+        let graph = GraphBuilder::build_random_graph_erdos_renyi(4, 5, 1.0).unwrap();
+
+        assert_eq!(graph.number_of_edges(), 4 * 5);
+    }
+
+    #[test]
+    fn erdos_renyi_rejects_a_probability_outside_the_unit_interval() {
+        assert!(GraphBuilder::build_random_graph_erdos_renyi(4, 5, 1.5).is_err());
+        assert!(GraphBuilder::build_random_graph_erdos_renyi(4, 5, -0.1).is_err());
+    }
+
+    #[test]
+    fn degree_distribution_graph_clamps_degree_to_the_number_of_fixed_nodes_and_stays_simple() {
+        let number_of_fixed_nodes = 3;
+        let number_of_free_nodes = 5;
+
+        // Every free node asks for more neighbors than there are fixed nodes, so the clamp must
+        // kick in; since fixed nodes are drawn without replacement, the result is also simple.
+        let graph = GraphBuilder::build_random_graph_with_degree_distribution(
+            number_of_fixed_nodes,
+            number_of_free_nodes,
+            || 10,
+        );
+
+        assert_eq!(graph.number_of_edges(), number_of_fixed_nodes * number_of_free_nodes);
+    }
+
+    #[test]
+    fn degree_distribution_graph_respects_a_degree_of_zero() {
+        let graph = GraphBuilder::build_random_graph_with_degree_distribution(3, 5, || 0);
+
+        assert_eq!(graph.number_of_edges(), 0);
+    }
+}